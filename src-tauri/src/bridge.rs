@@ -6,15 +6,66 @@
 //! - Async command/response channels for Tauri commands
 //! - Handle management for query results
 
-use crate::rayforce_ffi::{self, ObjP, TYPE_ERR, TYPE_TABLE};
+use crate::client::RemoteClient;
+use crate::conversion::{canonical_type, convert_or_raw, Conversion};
+use crate::rayforce_ffi::{self, ObjP, TYPE_ERR, TYPE_LIST, TYPE_TABLE};
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::CString;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::error::TryRecvError;
 use tokio::sync::{mpsc, oneshot};
 
+/// Error returned when a query is stopped by a cancellation token.
+///
+/// Distinct from a genuine failure so the frontend can tell a user cancel
+/// apart from an execution error.
+pub const QUERY_CANCELLED: &str = "QueryCancelled";
+
+/// A cooperative, best-effort cancellation flag for a query.
+///
+/// `librayforce` exposes no interrupt/abort hook for a running `eval_str` —
+/// nothing in `rayforce_ffi` can pre-empt it mid-flight — so this is the whole
+/// contract, by design, not an interim step toward a harder guarantee: the
+/// flag is checked at the boundaries around the call instead. A query
+/// cancelled before it starts is skipped, and one cancelled while its eval is
+/// running has its result dropped (and memory freed on the Rayforce thread)
+/// the moment the eval returns instead of being stored under a handle. It
+/// shortens the window and reclaims the result's memory; it does not stop a
+/// runaway evaluation, which keeps running to completion on the Rayforce
+/// thread regardless.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a fresh, un-cancelled token.
+    pub fn new() -> Self {
+        Self(Arc::new(AtomicBool::new(false)))
+    }
+
+    /// Trip the token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether the token has been tripped.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Shared map of in-flight query ids to their cancellation token.
+///
+/// A query's token is tripped by `cancel_query`; the Rayforce thread checks it
+/// around the eval and drops any intermediate result so a cancelled query frees
+/// its memory promptly rather than leaving it stored under an unread handle.
+type PendingQueries = Arc<Mutex<HashMap<String, CancellationToken>>>;
+
 // =============================================================================
 // Types
 // =============================================================================
@@ -33,6 +84,58 @@ pub struct QueryMeta {
 /// A single row of data
 pub type Row = HashMap<String, serde_json::Value>;
 
+/// One chunk delivered over a subscription: a batch of rows or an error.
+pub type SubscriptionChunk = Result<Vec<Row>, String>;
+
+/// A column read in bulk straight from a vector's inline buffer.
+///
+/// Reading a whole column once avoids the O(rows × cols) `at_idx` round-trips
+/// that the per-cell path incurs. Symbol columns and nested lists can't be
+/// read as a flat primitive buffer, so they are materialized element-by-element
+/// into the [`ColumnData::Json`] fallback.
+pub enum ColumnData {
+    Bool(Vec<bool>),
+    Byte(Vec<u8>),
+    I16(Vec<i16>),
+    I32(Vec<i32>),
+    I64(Vec<i64>),
+    F64(Vec<f64>),
+    Char(String),
+    Json(Vec<serde_json::Value>),
+}
+
+impl ColumnData {
+    /// Materialize the column as a vector of JSON values, one per row.
+    pub fn into_json(self) -> Vec<serde_json::Value> {
+        match self {
+            ColumnData::Bool(v) => v.into_iter().map(|b| serde_json::json!(b)).collect(),
+            ColumnData::Byte(v) => v.into_iter().map(|b| serde_json::json!(b)).collect(),
+            ColumnData::I16(v) => v.into_iter().map(|n| serde_json::json!(n)).collect(),
+            ColumnData::I32(v) => v.into_iter().map(|n| serde_json::json!(n)).collect(),
+            ColumnData::I64(v) => v.into_iter().map(|n| serde_json::json!(n)).collect(),
+            ColumnData::F64(v) => v.into_iter().map(|n| serde_json::json!(n)).collect(),
+            ColumnData::Char(s) => s
+                .chars()
+                .map(|c| serde_json::json!(c.to_string()))
+                .collect(),
+            ColumnData::Json(v) => v,
+        }
+    }
+}
+
+/// Bound on in-flight subscription chunks before the producer coalesces.
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 8;
+
+/// Bound on in-flight streamed row chunks before the producer back-pressures.
+const STREAM_CHANNEL_CAPACITY: usize = 8;
+
+/// How long the Rayforce thread sleeps between streaming steps when producers
+/// are active but no command is pending, to avoid busy-spinning.
+const STREAM_POLL_IDLE_MS: u64 = 20;
+
+/// How often a live subscription re-evaluates its query to pick up appended rows.
+const SUBSCRIPTION_POLL_INTERVAL_MS: u64 = 200;
+
 /// Commands sent to the Rayforce thread
 pub enum RayCommand {
     /// Execute a query, store result, return metadata
@@ -48,10 +151,70 @@ pub enum RayCommand {
         count: u64,
         response: oneshot::Sender<Result<Vec<Row>, String>>,
     },
+    /// Get rows from a stored result, applying per-column conversions
+    GetRowsTyped {
+        handle: u64,
+        start: u64,
+        count: u64,
+        conversions: HashMap<String, Conversion>,
+        response: oneshot::Sender<Result<Vec<Row>, String>>,
+    },
+    /// Get a column-oriented payload from a stored table result
+    GetColumns {
+        handle: u64,
+        start: u64,
+        count: u64,
+        conversions: HashMap<String, Conversion>,
+        response: oneshot::Sender<Result<HashMap<String, Vec<serde_json::Value>>, String>>,
+    },
+    /// Stream rows from a stored result in chunks, driven by the thread.
+    StreamRows {
+        handle: u64,
+        start: u64,
+        /// Maximum rows to stream (0 means to the end of the result).
+        total: u64,
+        chunk_size: u64,
+        /// Bounded sink; a full channel back-pressures the producer.
+        sink: mpsc::Sender<SubscriptionChunk>,
+    },
+    /// Execute several queries in one submission, returning per-item results.
+    Batch {
+        queries: Vec<(String, String)>,
+        response: oneshot::Sender<Vec<Result<QueryMeta, String>>>,
+    },
     /// Release a handle (drop_obj)
     Release { handle: u64 },
-    /// Cancel a pending query
-    Cancel { query_id: String },
+    /// Register a streaming subscription that pushes row chunks as a result
+    /// becomes available.
+    Subscribe {
+        subscription_id: String,
+        code: String,
+        chunk_size: u64,
+        /// Tripped by `unsubscribe` to stop the producer mid-stream.
+        active: Arc<AtomicBool>,
+        /// Bounded sink; a slow consumer causes chunks to coalesce.
+        sink: mpsc::Sender<SubscriptionChunk>,
+    },
+    /// Tear down a subscription and drop any retained result.
+    Unsubscribe { subscription_id: String },
+    /// Open a TCP connection to a remote Rayforce server
+    Connect {
+        host: String,
+        port: u16,
+        response: oneshot::Sender<Result<(), String>>,
+    },
+    /// Close the TCP connection to the remote Rayforce server
+    Disconnect {
+        response: oneshot::Sender<Result<(), String>>,
+    },
+    /// Evaluate `code` on the remote server and store the reply as a handle
+    QueryRemote {
+        query_id: String,
+        code: String,
+        /// When false, fire-and-forget (no reply awaited)
+        wait: bool,
+        response: oneshot::Sender<Result<Option<QueryMeta>, String>>,
+    },
     /// Shutdown the Rayforce thread
     Shutdown,
 }
@@ -79,8 +242,11 @@ pub struct RayforceBridge {
     thread_handle: Mutex<Option<JoinHandle<()>>>,
     /// Whether the bridge is running
     running: AtomicBool,
-    /// Cancelled query IDs (checked before storing results)
-    cancelled: Mutex<std::collections::HashSet<String>>,
+    /// In-flight queries and their cancellation tokens. Tripping a query's
+    /// token is the one and only cancellation signal.
+    pending: PendingQueries,
+    /// Active subscriptions and their stop flags
+    subscriptions: Mutex<HashMap<String, Arc<AtomicBool>>>,
 }
 
 impl RayforceBridge {
@@ -93,7 +259,8 @@ impl RayforceBridge {
             command_rx: Mutex::new(Some(command_rx)),
             thread_handle: Mutex::new(None),
             running: AtomicBool::new(false),
-            cancelled: Mutex::new(std::collections::HashSet::new()),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            subscriptions: Mutex::new(HashMap::new()),
         };
 
         Ok(bridge)
@@ -108,9 +275,10 @@ impl RayforceBridge {
         // Take the receiver from storage
         let rx = self.command_rx.lock().take()
             .expect("start() called but receiver already taken");
+        let pending = Arc::clone(&self.pending);
 
         let handle = thread::spawn(move || {
-            rayforce_thread_main(rx);
+            rayforce_thread_main(rx, pending);
         });
 
         *self.thread_handle.lock() = Some(handle);
@@ -124,19 +292,60 @@ impl RayforceBridge {
     ) -> Result<QueryMeta, String> {
         let (response_tx, response_rx) = oneshot::channel();
 
-        self.command_tx
+        // Register the query's cancellation token before dispatch so a cancel
+        // arriving before the eval starts skips it, and one arriving while it
+        // runs drops the result on return. The entry is removed by the Rayforce
+        // thread when the query finishes, so the map never accumulates.
+        let token = CancellationToken::new();
+        self.pending.lock().insert(query_id.clone(), token);
+
+        if self
+            .command_tx
             .send(RayCommand::Execute {
-                query_id,
+                query_id: query_id.clone(),
                 code,
                 response: response_tx,
             })
-            .map_err(|_| "Failed to send command to Rayforce thread")?;
+            .is_err()
+        {
+            self.pending.lock().remove(&query_id);
+            return Err("Failed to send command to Rayforce thread".to_string());
+        }
 
         response_rx
             .await
             .map_err(|_| "Rayforce thread dropped response channel")?
     }
 
+    /// Execute a query, giving up on its result if it runs past `timeout`.
+    ///
+    /// Races the evaluation against the deadline. On timeout the caller is
+    /// unblocked with `Err("timed out")`, dropping this future and its
+    /// `response_rx`, and `query_id` is cancelled. When the eval eventually
+    /// returns on the Rayforce thread one of two things happens: the token
+    /// was already tripped and the result is dropped without being stored, or
+    /// it wasn't (the eval outran the cancel) and the result is stored then
+    /// immediately released when `response.send` fails because nobody is
+    /// listening. Either path frees the result; neither stores a handle this
+    /// caller could ever learn. The evaluation itself is opaque and keeps
+    /// running to completion on that thread — the deadline bounds how long
+    /// the caller waits, not the compute.
+    pub async fn execute_query_with_timeout(
+        &self,
+        query_id: String,
+        code: String,
+        timeout: std::time::Duration,
+    ) -> Result<QueryMeta, String> {
+        let fut = self.execute_query(query_id.clone(), code);
+        match tokio::time::timeout(timeout, fut).await {
+            Ok(result) => result,
+            Err(_) => {
+                let _ = self.cancel_query(query_id).await;
+                Err("timed out".to_string())
+            }
+        }
+    }
+
     /// Get rows from a stored result
     pub async fn get_rows(
         &self,
@@ -160,6 +369,59 @@ impl RayforceBridge {
             .map_err(|_| "Rayforce thread dropped response channel")?
     }
 
+    /// Get rows from a stored result, applying per-column conversions
+    pub async fn get_rows_typed(
+        &self,
+        handle: u64,
+        start: u64,
+        count: u64,
+        conversions: HashMap<String, Conversion>,
+    ) -> Result<Vec<Row>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.command_tx
+            .send(RayCommand::GetRowsTyped {
+                handle,
+                start,
+                count,
+                conversions,
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel")?
+    }
+
+    /// Get a column-oriented payload from a stored table result.
+    ///
+    /// Transfers one array per column (read in bulk from the inline buffers)
+    /// instead of N row objects.
+    pub async fn get_columns(
+        &self,
+        handle: u64,
+        start: u64,
+        count: u64,
+        conversions: HashMap<String, Conversion>,
+    ) -> Result<HashMap<String, Vec<serde_json::Value>>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        self.command_tx
+            .send(RayCommand::GetColumns {
+                handle,
+                start,
+                count,
+                conversions,
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel")?
+    }
+
     /// Release a handle
     pub async fn release_handle(&self, handle: u64) -> Result<(), String> {
         self.command_tx
@@ -168,11 +430,171 @@ impl RayforceBridge {
         Ok(())
     }
 
-    /// Cancel a pending query
+    /// Cancel a pending query (cooperative, best-effort — see
+    /// [`CancellationToken`]).
+    ///
+    /// Trips the query's cancellation token. A query that has not yet started is
+    /// skipped when the thread reaches it; one whose eval is already running has
+    /// its result dropped and freed the moment that eval returns. The `eval_str`
+    /// call itself is opaque and is not pre-empted — this is not an in-flight
+    /// abort, and a runaway evaluation still runs to completion. There is no
+    /// lower-level hook to change that; accept the result or track it as a
+    /// `librayforce` feature request, not a gap in this layer.
     pub async fn cancel_query(&self, query_id: String) -> Result<(), String> {
-        self.cancelled.lock().insert(query_id.clone());
+        // Trip the query's token. If it has not started yet the Rayforce thread
+        // skips it when it reaches it; if its eval is already running, the
+        // result is dropped and freed the moment that eval returns.
+        if let Some(token) = self.pending.lock().get(&query_id) {
+            token.cancel();
+        }
+        Ok(())
+    }
+
+    /// Open a TCP connection to a remote Rayforce server
+    pub async fn connect(&self, host: String, port: u16) -> Result<(), String> {
+        let (response_tx, response_rx) = oneshot::channel();
         self.command_tx
-            .send(RayCommand::Cancel { query_id })
+            .send(RayCommand::Connect {
+                host,
+                port,
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel")?
+    }
+
+    /// Close the TCP connection to the remote Rayforce server
+    pub async fn disconnect(&self) -> Result<(), String> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.command_tx
+            .send(RayCommand::Disconnect {
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel")?
+    }
+
+    /// Evaluate a query on the remote server.
+    ///
+    /// When `wait` is true this blocks for the decoded reply and returns its
+    /// metadata; when false it is fire-and-forget and returns `Ok(None)`.
+    pub async fn query_remote(
+        &self,
+        query_id: String,
+        code: String,
+        wait: bool,
+    ) -> Result<Option<QueryMeta>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.command_tx
+            .send(RayCommand::QueryRemote {
+                query_id,
+                code,
+                wait,
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel")?
+    }
+
+    /// Stream rows from a stored result as a [`Stream`](futures::Stream).
+    ///
+    /// The Rayforce thread drives delivery, pushing chunks of `chunk_size`
+    /// rows from `start` until `total` rows (or the end of the result) are
+    /// emitted. A bounded channel back-pressures the producer so a slow
+    /// consumer doesn't let it run away.
+    pub fn stream_rows(
+        &self,
+        handle: u64,
+        start: u64,
+        total: u64,
+        chunk_size: u64,
+    ) -> Result<impl futures::Stream<Item = SubscriptionChunk>, String> {
+        let (sink, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+        self.command_tx
+            .send(RayCommand::StreamRows {
+                handle,
+                start,
+                total,
+                chunk_size: chunk_size.max(1),
+                sink,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+
+        Ok(futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|item| (item, rx))
+        }))
+    }
+
+    /// Execute several queries in one submission.
+    ///
+    /// Runs the queries sequentially on the Rayforce thread so later queries
+    /// observe side effects (like a preceding `set`) of earlier ones, and
+    /// returns one result per query in submission order. Each successful query
+    /// gets its own handle for pagination.
+    pub async fn execute_batch(
+        &self,
+        queries: Vec<(String, String)>,
+    ) -> Result<Vec<Result<QueryMeta, String>>, String> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.command_tx
+            .send(RayCommand::Batch {
+                queries,
+                response: response_tx,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+        response_rx
+            .await
+            .map_err(|_| "Rayforce thread dropped response channel".to_string())
+    }
+
+    /// Register a streaming subscription.
+    ///
+    /// Returns the receiver for row chunks; the Rayforce thread evaluates
+    /// `code` and pushes chunks of `chunk_size` rows as they become available.
+    /// A slow consumer causes chunks to coalesce rather than buffer unbounded.
+    pub fn subscribe(
+        &self,
+        subscription_id: String,
+        code: String,
+        chunk_size: u64,
+    ) -> Result<mpsc::Receiver<SubscriptionChunk>, String> {
+        // Reject an invalid query before registering anything, so a rejected
+        // subscription never leaves a dangling entry in `self.subscriptions`.
+        if code.as_bytes().contains(&0) {
+            return Err("Invalid query string: contains interior NUL".to_string());
+        }
+
+        let (sink, rx) = mpsc::channel(SUBSCRIPTION_CHANNEL_CAPACITY);
+        let active = Arc::new(AtomicBool::new(true));
+        self.subscriptions
+            .lock()
+            .insert(subscription_id.clone(), Arc::clone(&active));
+
+        self.command_tx
+            .send(RayCommand::Subscribe {
+                subscription_id,
+                code,
+                chunk_size,
+                active,
+                sink,
+            })
+            .map_err(|_| "Failed to send command to Rayforce thread")?;
+        Ok(rx)
+    }
+
+    /// Tear down a subscription: stop the producer and drop its retained result.
+    pub fn unsubscribe(&self, subscription_id: String) -> Result<(), String> {
+        if let Some(active) = self.subscriptions.lock().remove(&subscription_id) {
+            active.store(false, Ordering::SeqCst);
+        }
+        self.command_tx
+            .send(RayCommand::Unsubscribe { subscription_id })
             .map_err(|_| "Failed to send command to Rayforce thread")?;
         Ok(())
     }
@@ -199,7 +621,10 @@ impl Drop for RayforceBridge {
 // =============================================================================
 
 /// Main function for the Rayforce thread
-fn rayforce_thread_main(mut command_rx: mpsc::UnboundedReceiver<RayCommand>) {
+fn rayforce_thread_main(
+    mut command_rx: mpsc::UnboundedReceiver<RayCommand>,
+    pending: PendingQueries,
+) {
     log::info!("Rayforce thread starting");
 
     // Initialize Rayforce runtime
@@ -230,25 +655,63 @@ fn rayforce_thread_main(mut command_rx: mpsc::UnboundedReceiver<RayCommand>) {
     let mut handles: HashMap<u64, ObjP> = HashMap::new();
     let mut next_handle: u64 = 1;
 
-    // Cancelled query IDs
-    let mut cancelled: std::collections::HashSet<String> = std::collections::HashSet::new();
+    // Remote IPC client (set via RayCommand::Connect)
+    let mut remote: Option<RemoteClient> = None;
+
+    // Active stream/subscription producers. They are advanced one chunk at a
+    // time between commands so a long-running or live stream never monopolises
+    // the single Rayforce thread — other queries, pagination and cancels are
+    // still serviced while a stream is in progress.
+    let mut row_streams: Vec<RowStream> = Vec::new();
+    let mut subscriptions: HashMap<String, LiveSub> = HashMap::new();
+
+    // Command loop. While producers are active we poll for commands without
+    // blocking and make one unit of streaming progress per turn; when idle we
+    // block on the next command.
+    loop {
+        let producers_active = !row_streams.is_empty() || !subscriptions.is_empty();
+        let cmd: Option<RayCommand> = if producers_active {
+            match command_rx.try_recv() {
+                Ok(c) => Some(c),
+                Err(TryRecvError::Empty) => None,
+                Err(TryRecvError::Disconnected) => break,
+            }
+        } else {
+            match command_rx.blocking_recv() {
+                Some(c) => Some(c),
+                None => break,
+            }
+        };
+        let got_cmd = cmd.is_some();
 
-    // Command loop (blocking receive in sync context)
-    while let Some(cmd) = command_rx.blocking_recv() {
-        match cmd {
+        if let Some(cmd) = cmd {
+            match cmd {
             RayCommand::Execute {
                 query_id,
                 code,
                 response,
             } => {
-                // Check if cancelled
-                if cancelled.remove(&query_id) {
-                    let _ = response.send(Err("Query cancelled".to_string()));
-                    continue;
+                // The token was registered when the query was submitted. If a
+                // cancel arrived before we dequeued it, skip the eval entirely;
+                // otherwise run it, passing the token so a cancel landing mid-
+                // eval drops the result on return.
+                let token = pending.lock().get(&query_id).cloned().unwrap_or_default();
+                let result = if token.is_cancelled() {
+                    Err(QUERY_CANCELLED.to_string())
+                } else {
+                    execute_query_impl(&code, &mut handles, &mut next_handle, &token)
+                };
+                pending.lock().remove(&query_id);
+
+                // If the caller's future was dropped (e.g. it lost a race
+                // against a timeout), nobody will ever call release_handle for
+                // this result — free it here instead of leaking it in
+                // `handles` forever.
+                if let Err(Ok(meta)) = response.send(result) {
+                    if let Some(obj) = handles.remove(&meta.handle) {
+                        unsafe { rayforce_ffi::drop_obj(obj) };
+                    }
                 }
-
-                let result = execute_query_impl(&code, &mut handles, &mut next_handle);
-                let _ = response.send(result);
             }
 
             RayCommand::GetRows {
@@ -261,6 +724,55 @@ fn rayforce_thread_main(mut command_rx: mpsc::UnboundedReceiver<RayCommand>) {
                 let _ = response.send(result);
             }
 
+            RayCommand::GetRowsTyped {
+                handle,
+                start,
+                count,
+                conversions,
+                response,
+            } => {
+                let result = get_rows_typed_impl(handle, start, count, &conversions, &handles);
+                let _ = response.send(result);
+            }
+
+            RayCommand::GetColumns {
+                handle,
+                start,
+                count,
+                conversions,
+                response,
+            } => {
+                let result = get_columns_impl(handle, start, count, &conversions, &handles);
+                let _ = response.send(result);
+            }
+
+            RayCommand::StreamRows {
+                handle,
+                start,
+                total,
+                chunk_size,
+                sink,
+            } => {
+                // Register the producer; it is advanced incrementally below so
+                // the command loop stays responsive for the whole stream.
+                row_streams.push(RowStream::new(handle, start, total, chunk_size.max(1), sink));
+            }
+
+            RayCommand::Batch { queries, response } => {
+                let mut results = Vec::with_capacity(queries.len());
+                for (query_id, code) in queries {
+                    // Each item registers its own token so a cancel targeting
+                    // that query id drops its result on return; the entry is
+                    // cleared as soon as the item completes.
+                    let token = CancellationToken::new();
+                    pending.lock().insert(query_id.clone(), token.clone());
+                    let result = execute_query_impl(&code, &mut handles, &mut next_handle, &token);
+                    pending.lock().remove(&query_id);
+                    results.push(result);
+                }
+                let _ = response.send(results);
+            }
+
             RayCommand::Release { handle } => {
                 if let Some(obj) = handles.remove(&handle) {
                     unsafe { rayforce_ffi::drop_obj(obj) };
@@ -268,14 +780,81 @@ fn rayforce_thread_main(mut command_rx: mpsc::UnboundedReceiver<RayCommand>) {
                 }
             }
 
-            RayCommand::Cancel { query_id } => {
-                cancelled.insert(query_id);
+            RayCommand::Subscribe {
+                subscription_id,
+                code,
+                chunk_size,
+                active,
+                sink,
+            } => {
+                // Register a live subscription; it is polled incrementally
+                // below, appending newly arrived rows to the sink over time.
+                match LiveSub::new(&code, chunk_size.max(1), active, sink) {
+                    Some(sub) => {
+                        subscriptions.insert(subscription_id, sub);
+                    }
+                    None => log::warn!("Rejected subscription {}: invalid code", subscription_id),
+                }
+            }
+
+            RayCommand::Unsubscribe { subscription_id } => {
+                // Drop the producer so it stops polling; its per-poll result is
+                // already released, so nothing else is retained.
+                subscriptions.remove(&subscription_id);
+                log::debug!("Unsubscribed {}", subscription_id);
+            }
+
+            RayCommand::Connect {
+                host,
+                port,
+                response,
+            } => {
+                let result = RemoteClient::connect(&host, port).map(|client| {
+                    log::info!("Connected to remote Rayforce server {}:{}", host, port);
+                    remote = Some(client);
+                });
+                let _ = response.send(result);
+            }
+
+            RayCommand::Disconnect { response } => {
+                remote = None; // TcpStream closes on drop
+                let _ = response.send(Ok(()));
+            }
+
+            RayCommand::QueryRemote {
+                query_id: _,
+                code,
+                wait,
+                response,
+            } => {
+                // Remote queries run to completion over the socket and have no
+                // local eval to skip, so there is no pre-start cancel check.
+                let result = remote_query_impl(
+                    remote.as_mut(),
+                    &code,
+                    wait,
+                    &mut handles,
+                    &mut next_handle,
+                );
+                let _ = response.send(result);
             }
 
             RayCommand::Shutdown => {
                 log::info!("Rayforce thread shutting down");
                 break;
             }
+            }
+        }
+
+        // Advance each streaming producer by one chunk, dropping any that have
+        // finished (end of result, closed receiver, or a hard error).
+        row_streams.retain_mut(|s| !advance_row_stream(s, &handles));
+        subscriptions.retain(|_, sub| !advance_live_sub(sub));
+
+        // When producers are active but no command arrived this turn, pause
+        // briefly so we don't busy-spin the thread between chunks.
+        if producers_active && !got_cmd {
+            std::thread::sleep(std::time::Duration::from_millis(STREAM_POLL_IDLE_MS));
         }
     }
 
@@ -295,6 +874,7 @@ fn execute_query_impl(
     code: &str,
     handles: &mut HashMap<u64, ObjP>,
     next_handle: &mut u64,
+    token: &CancellationToken,
 ) -> Result<QueryMeta, String> {
     log::debug!("Executing query: {}", code);
 
@@ -305,6 +885,14 @@ fn execute_query_impl(
         return Err("Query returned null".to_string());
     }
 
+    // If the token fired while `eval_str` ran, free the intermediate result on
+    // this (the Rayforce) thread and report a distinct cancellation rather
+    // than storing a handle nobody will ever read.
+    if token.is_cancelled() {
+        unsafe { rayforce_ffi::drop_obj(result) };
+        return Err(QUERY_CANCELLED.to_string());
+    }
+
     // Check for error
     let obj = unsafe { &*result };
 
@@ -332,6 +920,265 @@ fn execute_query_impl(
     Ok(meta)
 }
 
+/// Evaluate a query on the remote server over the IPC client.
+///
+/// Parses `code` locally into a request object, ships it to the server, and
+/// (when `wait`) stores the decoded reply as a handle and returns its
+/// metadata. When `wait` is false the request is fire-and-forget.
+fn remote_query_impl(
+    client: Option<&mut RemoteClient>,
+    code: &str,
+    wait: bool,
+    handles: &mut HashMap<u64, ObjP>,
+    next_handle: &mut u64,
+) -> Result<Option<QueryMeta>, String> {
+    let client = client.ok_or("Not connected to a remote server")?;
+
+    let c_code = CString::new(code).map_err(|e| format!("Invalid query string: {}", e))?;
+    let request = unsafe { rayforce_ffi::parse_str(c_code.as_ptr()) };
+    if request.is_null() {
+        return Err("Failed to parse remote query".to_string());
+    }
+
+    if !wait {
+        let result = unsafe { client.query_remote_async(request) };
+        unsafe { rayforce_ffi::drop_obj(request) };
+        return result.map(|()| None);
+    }
+
+    let reply = unsafe { client.query_remote(request) };
+    unsafe { rayforce_ffi::drop_obj(request) };
+    let reply = reply?;
+
+    let handle = *next_handle;
+    *next_handle += 1;
+    handles.insert(handle, reply);
+
+    let meta = extract_query_meta(handle, reply)?;
+    Ok(Some(meta))
+}
+
+/// Incremental producer that streams rows of a stored result in chunks.
+///
+/// Advanced one chunk per call to [`advance_row_stream`] so it never blocks the
+/// command loop. A chunk that can't be delivered because the bounded sink is
+/// full is parked in `pending` and retried on the next turn, which back-
+/// pressures the producer without buffering unboundedly.
+struct RowStream {
+    handle: u64,
+    cursor: u64,
+    /// Rows still to stream (`u64::MAX` means to the end of the result).
+    remaining: u64,
+    chunk_size: u64,
+    sink: mpsc::Sender<SubscriptionChunk>,
+    /// Chunk read but not yet accepted by a full sink.
+    pending: Option<Vec<Row>>,
+    /// Whether the result has been exhausted.
+    ended: bool,
+}
+
+impl RowStream {
+    fn new(
+        handle: u64,
+        start: u64,
+        total: u64,
+        chunk_size: u64,
+        sink: mpsc::Sender<SubscriptionChunk>,
+    ) -> Self {
+        Self {
+            handle,
+            cursor: start,
+            remaining: if total == 0 { u64::MAX } else { total },
+            chunk_size,
+            sink,
+            pending: None,
+            ended: false,
+        }
+    }
+}
+
+/// Advance a row stream by at most one chunk; returns `true` when it is
+/// finished (result exhausted, receiver dropped, or a read error) and should be
+/// removed.
+fn advance_row_stream(stream: &mut RowStream, handles: &HashMap<u64, ObjP>) -> bool {
+    // Retry a parked chunk before reading more.
+    if let Some(rows) = stream.pending.take() {
+        match stream.sink.try_send(Ok(rows)) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(Ok(rows))) => {
+                stream.pending = Some(rows);
+                return false;
+            }
+            Err(_) => return true,
+        }
+    }
+
+    if stream.ended || stream.remaining == 0 {
+        return true;
+    }
+
+    let want = std::cmp::min(stream.chunk_size, stream.remaining);
+    match get_rows_impl(stream.handle, stream.cursor, want, handles) {
+        Ok(rows) => {
+            if rows.is_empty() {
+                return true;
+            }
+            let n = rows.len() as u64;
+            stream.cursor += n;
+            if stream.remaining != u64::MAX {
+                stream.remaining -= n;
+            }
+            if n < want {
+                stream.ended = true;
+            }
+            match stream.sink.try_send(Ok(rows)) {
+                Ok(()) => stream.ended || stream.remaining == 0,
+                Err(mpsc::error::TrySendError::Full(Ok(rows))) => {
+                    stream.pending = Some(rows);
+                    false
+                }
+                Err(_) => true,
+            }
+        }
+        Err(e) => {
+            let _ = stream.sink.try_send(Err(e));
+            true
+        }
+    }
+}
+
+/// Live subscription producer: periodically re-evaluates `code` and appends the
+/// rows that have arrived since the last delivery, so the consumer sees a
+/// growing tick table rather than a one-shot snapshot.
+///
+/// Advanced by [`advance_live_sub`]. Unlike a row stream it never finishes on
+/// its own — it keeps emitting newly appended rows until the subscription is
+/// torn down (the stop flag is tripped or the receiver drops). Each poll's
+/// result is read and released within the same turn; only the delivered
+/// high-water mark is carried across polls.
+struct LiveSub {
+    code: CString,
+    chunk_size: u64,
+    active: Arc<AtomicBool>,
+    sink: mpsc::Sender<SubscriptionChunk>,
+    /// Rows already delivered to the consumer.
+    delivered: u64,
+    /// Chunk read but not yet accepted by a full sink.
+    pending: Option<Vec<Row>>,
+    /// Earliest instant at which the next re-evaluation may run.
+    next_poll: Instant,
+}
+
+impl LiveSub {
+    fn new(
+        code: &str,
+        chunk_size: u64,
+        active: Arc<AtomicBool>,
+        sink: mpsc::Sender<SubscriptionChunk>,
+    ) -> Option<Self> {
+        let code = match CString::new(code) {
+            Ok(c) => c,
+            Err(e) => {
+                let _ = sink.try_send(Err(format!("Invalid query string: {}", e)));
+                return None;
+            }
+        };
+        Some(Self {
+            code,
+            chunk_size,
+            active,
+            sink,
+            delivered: 0,
+            pending: None,
+            next_poll: Instant::now(),
+        })
+    }
+}
+
+/// Advance a live subscription; returns `true` when it should be dropped (stop
+/// flag tripped, receiver gone, or a hard evaluation error).
+fn advance_live_sub(sub: &mut LiveSub) -> bool {
+    if !sub.active.load(Ordering::SeqCst) {
+        return true;
+    }
+
+    // Retry a parked chunk before polling for more rows.
+    if let Some(rows) = sub.pending.take() {
+        match sub.sink.try_send(Ok(rows)) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(Ok(rows))) => {
+                sub.pending = Some(rows);
+                return false;
+            }
+            Err(_) => return true,
+        }
+    }
+
+    if Instant::now() < sub.next_poll {
+        return false;
+    }
+    sub.next_poll = Instant::now() + Duration::from_millis(SUBSCRIPTION_POLL_INTERVAL_MS);
+
+    // Re-evaluate the query to observe any rows appended since last time.
+    let result = unsafe { rayforce_ffi::eval_str(sub.code.as_ptr()) };
+    if result.is_null() {
+        let _ = sub.sink.try_send(Err("Query returned null".to_string()));
+        return true;
+    }
+    if unsafe { (*result).is_error() } {
+        let msg = extract_error_message(result);
+        unsafe { rayforce_ffi::drop_obj(result) };
+        let _ = sub.sink.try_send(Err(msg));
+        return true;
+    }
+
+    // Retain under a transient handle for the row reader and guarantee the
+    // result is freed on this (the Rayforce) thread before returning.
+    let mut handles: HashMap<u64, ObjP> = HashMap::new();
+    handles.insert(0, result);
+    let drop_result = |handles: &mut HashMap<u64, ObjP>| {
+        if let Some(obj) = handles.remove(&0) {
+            unsafe { rayforce_ffi::drop_obj(obj) };
+        }
+    };
+
+    let total = match extract_query_meta(0, result) {
+        Ok(meta) => meta.row_count,
+        Err(e) => {
+            drop_result(&mut handles);
+            let _ = sub.sink.try_send(Err(e));
+            return true;
+        }
+    };
+
+    if total <= sub.delivered {
+        drop_result(&mut handles); // nothing new yet
+        return false;
+    }
+
+    let want = std::cmp::min(sub.chunk_size, total - sub.delivered);
+    let outcome = match get_rows_impl(0, sub.delivered, want, &handles) {
+        Ok(rows) if rows.is_empty() => false,
+        Ok(rows) => {
+            sub.delivered += rows.len() as u64;
+            match sub.sink.try_send(Ok(rows)) {
+                Ok(()) => false,
+                Err(mpsc::error::TrySendError::Full(Ok(rows))) => {
+                    sub.pending = Some(rows);
+                    false
+                }
+                Err(_) => true,
+            }
+        }
+        Err(e) => {
+            let _ = sub.sink.try_send(Err(e));
+            true
+        }
+    };
+    drop_result(&mut handles);
+    outcome
+}
+
 /// Extract metadata from a query result
 fn extract_query_meta(handle: u64, obj: ObjP) -> Result<QueryMeta, String> {
     let obj_ref = unsafe { &*obj };
@@ -395,9 +1242,17 @@ fn extract_table_meta(obj: ObjP) -> Result<(Vec<String>, HashMap<String, String>
         }
     }
 
-    // Get values to determine row count
+    // Get values to determine row count and per-column types.
     let values = unsafe { rayforce_ffi::ray_value(obj) };
     let row_count = if !values.is_null() {
+        for (i, name) in columns.iter().enumerate() {
+            let col_vec = unsafe { rayforce_ffi::at_idx(values, i as i64) };
+            if !col_vec.is_null() {
+                let type_ = unsafe { (*col_vec).type_ };
+                column_types.insert(name.clone(), canonical_type(type_).to_string());
+            }
+        }
+
         // Values is a list of column vectors, get length of first column
         let first_col = unsafe { rayforce_ffi::at_idx(values, 0) };
         if !first_col.is_null() {
@@ -427,16 +1282,100 @@ fn extract_dict_meta(obj: ObjP) -> Result<(Vec<String>, HashMap<String, String>,
     let keys_ref = unsafe { &*keys };
     let num_keys = unsafe { keys_ref.len() as usize };
 
+    let values = unsafe { rayforce_ffi::ray_value(obj) };
     let mut columns = Vec::with_capacity(num_keys);
+    let mut column_types = HashMap::new();
     for i in 0..num_keys {
         let ray_key = unsafe { rayforce_ffi::at_idx(keys, i as i64) };
         if !ray_key.is_null() {
-            columns.push(symbol_to_string(ray_key));
+            let name = symbol_to_string(ray_key);
+            if !values.is_null() {
+                let val = unsafe { rayforce_ffi::at_idx(values, i as i64) };
+                if !val.is_null() {
+                    let type_ = unsafe { (*val).type_ };
+                    column_types.insert(name.clone(), canonical_type(type_).to_string());
+                }
+            }
+            columns.push(name);
         }
     }
 
     // Dict has 1 "row"
-    Ok((columns, HashMap::new(), 1))
+    Ok((columns, column_types, 1))
+}
+
+thread_local! {
+    /// Interned symbol id -> resolved name.
+    ///
+    /// Confined to the Rayforce thread (same invariant as the handles/`eval_str`
+    /// calls around it), so a plain `RefCell` is enough — no locking needed.
+    /// Every cell of a symbol column round-trips through `eval_obj` on a miss,
+    /// so without this, rendering an N-row symbol column costs N round-trips
+    /// instead of (distinct symbols).
+    static SYMBOL_CACHE: RefCell<HashMap<i64, String>> = RefCell::new(HashMap::new());
+}
+
+/// Resolve a symbol atom's interned id back to its name.
+///
+/// The runtime exposes no FFI to walk the intern table directly, so this goes
+/// through the language itself: build the call `(string; sym)` as an object —
+/// `clone_obj` the atom so the call doesn't free the caller's copy, wrap it
+/// with a fresh `string` symbol in a 2-element list — and hand that to
+/// `eval_obj`, the same way a client would write `string \`foo`. Returns
+/// `None` if the round-trip fails for any reason, so the caller can fall back
+/// to a placeholder rather than propagate an error for a cosmetic lookup.
+fn resolve_symbol_name(sym: ObjP) -> Option<String> {
+    let fn_name = CString::new("string").ok()?;
+    let fn_sym = unsafe { rayforce_ffi::symbol(fn_name.as_ptr(), 6) };
+    if fn_sym.is_null() {
+        return None;
+    }
+
+    let call = unsafe { rayforce_ffi::vector(TYPE_LIST, 2) };
+    if call.is_null() {
+        unsafe { rayforce_ffi::drop_obj(fn_sym) };
+        return None;
+    }
+    unsafe {
+        let slots = (*call).data_ptr::<ObjP>() as *mut ObjP;
+        slots.write(fn_sym);
+        slots.add(1).write(rayforce_ffi::clone_obj(sym));
+    }
+
+    let result = unsafe { rayforce_ffi::eval_obj(call) };
+    if result.is_null() {
+        return None;
+    }
+    let result_ref = unsafe { &*result };
+    if result_ref.is_error() {
+        unsafe { rayforce_ffi::drop_obj(result) };
+        return None;
+    }
+
+    let name = match result_ref.type_ {
+        12 | -12 => {
+            let len = if result_ref.type_ > 0 {
+                unsafe { result_ref.len() as usize }
+            } else {
+                1
+            };
+            if len == 0 {
+                Some(String::new())
+            } else {
+                let data_ptr = unsafe { result_ref.data_ptr::<u8>() };
+                if data_ptr.is_null() {
+                    None
+                } else {
+                    let bytes = unsafe { std::slice::from_raw_parts(data_ptr, len) };
+                    Some(String::from_utf8_lossy(bytes).to_string())
+                }
+            }
+        }
+        _ => None,
+    };
+
+    unsafe { rayforce_ffi::drop_obj(result) };
+    name
 }
 
 /// Convert symbol object to string
@@ -446,11 +1385,17 @@ fn symbol_to_string(obj: ObjP) -> String {
     }
     let obj_ref = unsafe { &*obj };
 
-    // For symbol atoms (type -6), the data is a pointer to the interned string
     if obj_ref.type_ == -6 {
-        // Symbol data is stored differently - use eval to convert
-        // For now, use a simple index-based name
-        format!("col_{}", unsafe { obj_ref.as_i64() })
+        let id = unsafe { obj_ref.as_i64() };
+        if let Some(cached) = SYMBOL_CACHE.with(|cache| cache.borrow().get(&id).cloned()) {
+            return cached;
+        }
+        // Resolution genuinely fails only if the round-trip errors or the
+        // runtime returns something that isn't a string; fall back to the old
+        // id-based placeholder in that case rather than losing the column.
+        let name = resolve_symbol_name(obj).unwrap_or_else(|| format!("col_{}", id));
+        SYMBOL_CACHE.with(|cache| cache.borrow_mut().insert(id, name.clone()));
+        name
     } else if obj_ref.type_ == 12 || obj_ref.type_ == -12 {
         // C8 vector or char - it's a string
         let len = if obj_ref.type_ > 0 {
@@ -474,7 +1419,7 @@ fn symbol_to_string(obj: ObjP) -> String {
 }
 
 /// Extract error message from an error object
-fn extract_error_message(obj: ObjP) -> String {
+pub(crate) fn extract_error_message(obj: ObjP) -> String {
     if obj.is_null() {
         return "Unknown error".to_string();
     }
@@ -578,8 +1523,187 @@ fn get_rows_impl(
     }
 }
 
+/// Get rows from a stored result, applying per-column conversions.
+///
+/// Mirrors [`get_rows_impl`] but routes every cell through the column's
+/// [`Conversion`] (falling back to the raw JSON encoding for `Raw`/unmapped
+/// columns), so temporal and typed columns render correctly.
+fn get_rows_typed_impl(
+    handle: u64,
+    start: u64,
+    count: u64,
+    conversions: &HashMap<String, Conversion>,
+    handles: &HashMap<u64, ObjP>,
+) -> Result<Vec<Row>, String> {
+    let obj = handles
+        .get(&handle)
+        .ok_or_else(|| format!("Invalid handle: {}", handle))?;
+
+    let obj_ref = unsafe { &**obj };
+    let type_code = obj_ref.type_;
+    let value_conv = conversions.get("value");
+
+    match type_code {
+        t if t < 0 => {
+            if start > 0 {
+                return Ok(vec![]);
+            }
+            let mut row = HashMap::new();
+            row.insert("value".to_string(), cell_json(*obj, value_conv)?);
+            Ok(vec![row])
+        }
+        t if t >= 0 && t <= 12 => {
+            let total = unsafe { obj_ref.len() as u64 };
+            let actual_count = std::cmp::min(count, total.saturating_sub(start));
+            let mut rows = Vec::with_capacity(actual_count as usize);
+            for i in 0..actual_count {
+                let idx = start + i;
+                let elem = unsafe { rayforce_ffi::at_idx(*obj, idx as i64) };
+                let mut row = HashMap::new();
+                row.insert("value".to_string(), cell_json(elem, value_conv)?);
+                rows.push(row);
+            }
+            Ok(rows)
+        }
+        TYPE_TABLE => get_table_rows_typed(*obj, start, count, conversions),
+        99 => {
+            if start > 0 {
+                return Ok(vec![]);
+            }
+            let keys = unsafe { rayforce_ffi::ray_key(*obj) };
+            let values = unsafe { rayforce_ffi::ray_value(*obj) };
+            if keys.is_null() || values.is_null() {
+                return Ok(vec![HashMap::new()]);
+            }
+            let mut row = HashMap::new();
+            for i in 0..unsafe { (*keys).len() } {
+                let name = symbol_to_string(unsafe { rayforce_ffi::at_idx(keys, i) });
+                let val = unsafe { rayforce_ffi::at_idx(values, i) };
+                let conv = conversions.get(&name);
+                row.insert(name, cell_json(val, conv)?);
+            }
+            Ok(vec![row])
+        }
+        _ => Err(format!("Unsupported type: {}", type_code)),
+    }
+}
+
+/// Convert a single cell, honoring its column [`Conversion`] if present.
+fn cell_json(obj: ObjP, conv: Option<&Conversion>) -> Result<serde_json::Value, String> {
+    convert_or_raw(obj, conv, obj_to_json)
+}
+
+/// Bulk-read a `[start, start + count)` slice of a column vector.
+///
+/// Dispatches on the vector's `type_` and reads the inline buffer in one pass
+/// for every primitive type. Symbol vectors and nested lists fall back to
+/// per-element `at_idx`, since they are not a flat primitive buffer.
+fn read_column_slice(col_vec: ObjP, start: u64, count: u64) -> ColumnData {
+    let obj_ref = unsafe { &*col_vec };
+    let total = unsafe { obj_ref.len() as u64 };
+    let actual = std::cmp::min(count, total.saturating_sub(start)) as usize;
+    let s = start as usize;
+
+    // Read `actual` elements of type `T` starting at `s` from the inline buffer.
+    unsafe fn slice<T: Copy>(obj_ref: &rayforce_ffi::ObjT, s: usize, n: usize) -> Vec<T> {
+        let base = obj_ref.data_ptr::<T>();
+        (0..n).map(|i| *base.add(s + i)).collect()
+    }
+
+    match obj_ref.type_ {
+        1 => ColumnData::Bool(
+            unsafe { slice::<u8>(obj_ref, s, actual) }
+                .into_iter()
+                .map(|b| b != 0)
+                .collect(),
+        ),
+        2 => ColumnData::Byte(unsafe { slice::<u8>(obj_ref, s, actual) }),
+        3 => ColumnData::I16(unsafe { slice::<i16>(obj_ref, s, actual) }),
+        4 => ColumnData::I32(unsafe { slice::<i32>(obj_ref, s, actual) }),
+        // i64 and temporal columns share the 8-byte integer layout.
+        5 | 7 | 8 | 9 => ColumnData::I64(unsafe { slice::<i64>(obj_ref, s, actual) }),
+        10 => ColumnData::F64(unsafe { slice::<f64>(obj_ref, s, actual) }),
+        12 => {
+            let bytes = unsafe { slice::<u8>(obj_ref, s, actual) };
+            ColumnData::Char(String::from_utf8_lossy(&bytes).into_owned())
+        }
+        _ => {
+            // Symbols and nested lists: materialize element-wise.
+            let mut v = Vec::with_capacity(actual);
+            for i in 0..actual {
+                let elem = unsafe { rayforce_ffi::at_idx(col_vec, (s + i) as i64) };
+                v.push(obj_to_json(elem).unwrap_or(serde_json::Value::Null));
+            }
+            ColumnData::Json(v)
+        }
+    }
+}
+
+/// Read every column of a table once into a column-oriented payload.
+fn get_columns_impl(
+    handle: u64,
+    start: u64,
+    count: u64,
+    conversions: &HashMap<String, Conversion>,
+    handles: &HashMap<u64, ObjP>,
+) -> Result<HashMap<String, Vec<serde_json::Value>>, String> {
+    let obj = handles
+        .get(&handle)
+        .ok_or_else(|| format!("Invalid handle: {}", handle))?;
+
+    if unsafe { (**obj).type_ } != TYPE_TABLE {
+        return Err("GetColumns is only supported for tables".to_string());
+    }
+
+    let keys = unsafe { rayforce_ffi::ray_key(*obj) };
+    let values = unsafe { rayforce_ffi::ray_value(*obj) };
+    if keys.is_null() || values.is_null() {
+        return Ok(HashMap::new());
+    }
+
+    let num_cols = unsafe { (*keys).len() as usize };
+    let mut columns = HashMap::with_capacity(num_cols);
+
+    for col_idx in 0..num_cols {
+        let col_sym = unsafe { rayforce_ffi::at_idx(keys, col_idx as i64) };
+        let name = symbol_to_string(col_sym);
+        let col_vec = unsafe { rayforce_ffi::at_idx(values, col_idx as i64) };
+        if col_vec.is_null() {
+            continue;
+        }
+
+        let vals = match conversions.get(&name) {
+            Some(conv) if !matches!(conv, Conversion::Raw) => {
+                // Typed conversion needs the atom object, so read element-wise.
+                let total = unsafe { (*col_vec).len() as u64 };
+                let actual = std::cmp::min(count, total.saturating_sub(start));
+                let mut v = Vec::with_capacity(actual as usize);
+                for i in 0..actual {
+                    let elem = unsafe { rayforce_ffi::at_idx(col_vec, (start + i) as i64) };
+                    v.push(cell_json(elem, Some(conv))?);
+                }
+                v
+            }
+            _ => read_column_slice(col_vec, start, count).into_json(),
+        };
+        columns.insert(name, vals);
+    }
+
+    Ok(columns)
+}
+
 /// Get rows from a table
 fn get_table_rows(obj: ObjP, start: u64, count: u64) -> Result<Vec<Row>, String> {
+    get_table_rows_typed(obj, start, count, &HashMap::new())
+}
+
+/// Get rows from a table, applying per-column conversions.
+fn get_table_rows_typed(
+    obj: ObjP,
+    start: u64,
+    count: u64,
+    conversions: &HashMap<String, Conversion>,
+) -> Result<Vec<Row>, String> {
     let keys = unsafe { rayforce_ffi::ray_key(obj) };
     let values = unsafe { rayforce_ffi::ray_value(obj) };
 
@@ -606,21 +1730,37 @@ fn get_table_rows(obj: ObjP, start: u64, count: u64) -> Result<Vec<Row>, String>
     let total_rows = unsafe { first_ref.len() as u64 };
     let actual_count = std::cmp::min(count, total_rows.saturating_sub(start));
 
-    let mut rows = Vec::with_capacity(actual_count as usize);
+    // Read each column once into a typed Rust vector, then pivot into rows.
+    // This avoids the O(rows × cols) `at_idx` round-trips of the naive path.
+    let mut col_values: Vec<Vec<serde_json::Value>> = Vec::with_capacity(num_cols);
+    for col_idx in 0..num_cols {
+        let col_vec = unsafe { rayforce_ffi::at_idx(values, col_idx as i64) };
+        if col_vec.is_null() {
+            col_values.push(vec![serde_json::Value::Null; actual_count as usize]);
+            continue;
+        }
+        let vals = match conversions.get(&col_names[col_idx]) {
+            Some(conv) if !matches!(conv, Conversion::Raw) => {
+                let mut v = Vec::with_capacity(actual_count as usize);
+                for i in 0..actual_count {
+                    let elem = unsafe { rayforce_ffi::at_idx(col_vec, (start + i) as i64) };
+                    v.push(cell_json(elem, Some(conv))?);
+                }
+                v
+            }
+            _ => read_column_slice(col_vec, start, count).into_json(),
+        };
+        col_values.push(vals);
+    }
 
-    for row_idx in 0..actual_count {
-        let idx = start + row_idx;
+    let mut rows = Vec::with_capacity(actual_count as usize);
+    for row_idx in 0..actual_count as usize {
         let mut row = HashMap::new();
-
         for col_idx in 0..num_cols {
-            let col_vec = unsafe { rayforce_ffi::at_idx(values, col_idx as i64) };
-            if !col_vec.is_null() {
-                let elem = unsafe { rayforce_ffi::at_idx(col_vec, idx as i64) };
-                let value = obj_to_json(elem)?;
-                row.insert(col_names[col_idx].clone(), value);
+            if let Some(value) = col_values[col_idx].get(row_idx) {
+                row.insert(col_names[col_idx].clone(), value.clone());
             }
         }
-
         rows.push(row);
     }
 
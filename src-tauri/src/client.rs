@@ -0,0 +1,149 @@
+//! Remote Rayforce IPC client
+//!
+//! Connects `raylens` to a remote Rayforce server over TCP instead of (or in
+//! addition to) evaluating against the in-process runtime. A parsed query
+//! object is serialized with [`ser_obj`](rayforce_ffi::ser_obj), framed with a
+//! leading `i64` length prefix (matching [`size_obj`](rayforce_ffi::size_obj)),
+//! written to the socket, and the reply frame is rehydrated with
+//! [`de_obj`](rayforce_ffi::de_obj). The reply reaches the frontend through
+//! `commands::query_remote`, which stores it under a handle like a local
+//! result; `commands::execute_query` always evaluates in-process and does not
+//! use this client.
+//!
+//! Two RPC shapes mirror the ARTIQ runtime:
+//! - [`RemoteClient::query_remote`]: blocking, waits for and decodes the reply.
+//! - [`RemoteClient::query_remote_async`]: fire-and-forget, sends the request
+//!   and returns immediately (useful for writes and `set` operations).
+//!
+//! IMPORTANT: the serialization/deserialization calls touch the Rayforce
+//! runtime and therefore must run on the Rayforce thread, same as every other
+//! FFI call in this crate.
+
+use crate::rayforce_ffi::{self, ObjP, TYPE_ERR, TYPE_U8};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// A TCP connection to a remote Rayforce server.
+pub struct RemoteClient {
+    stream: TcpStream,
+    host: String,
+    port: u16,
+}
+
+impl RemoteClient {
+    /// Open a connection to `host:port`.
+    pub fn connect(host: &str, port: u16) -> Result<Self, String> {
+        let stream = TcpStream::connect((host, port))
+            .map_err(|e| format!("Failed to connect to {}:{}: {}", host, port, e))?;
+        Ok(Self {
+            stream,
+            host: host.to_string(),
+            port,
+        })
+    }
+
+    /// Host this client is connected to.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Port this client is connected to.
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Send a request object and block until the reply object is decoded.
+    ///
+    /// Takes a borrowed, parsed query object, serializes it, writes the framed
+    /// request, then reads the length-prefixed reply and rehydrates it. The
+    /// returned object is owned by the caller and must be freed with
+    /// `drop_obj` on the Rayforce thread. Server-side `TYPE_ERR` objects are
+    /// surfaced as a Rust error.
+    ///
+    /// Safety: must be called on the Rayforce thread.
+    pub unsafe fn query_remote(&mut self, request: ObjP) -> Result<ObjP, String> {
+        self.write_frame(request)?;
+
+        let buf = self.read_frame()?;
+        let reply = rayforce_ffi::de_obj(buf);
+        rayforce_ffi::drop_obj(buf);
+
+        if reply.is_null() {
+            return Err("Remote reply decoded to null".to_string());
+        }
+        if (*reply).type_ == TYPE_ERR {
+            let msg = crate::bridge::extract_error_message(reply);
+            rayforce_ffi::drop_obj(reply);
+            return Err(msg);
+        }
+
+        Ok(reply)
+    }
+
+    /// Send a request object without waiting for a reply (fire-and-forget).
+    ///
+    /// Safety: must be called on the Rayforce thread.
+    pub unsafe fn query_remote_async(&mut self, request: ObjP) -> Result<(), String> {
+        self.write_frame(request)
+    }
+
+    /// Serialize `obj`, frame it with an `i64` length prefix, and write it.
+    ///
+    /// Safety: must be called on the Rayforce thread.
+    unsafe fn write_frame(&mut self, obj: ObjP) -> Result<(), String> {
+        let buf = rayforce_ffi::ser_obj(obj);
+        if buf.is_null() {
+            return Err("Failed to serialize request object".to_string());
+        }
+
+        let len = rayforce_ffi::size_obj(obj);
+        let bytes = byte_slice(buf, len);
+
+        let header = len.to_le_bytes();
+        let result = self
+            .stream
+            .write_all(&header)
+            .and_then(|()| self.stream.write_all(bytes))
+            .and_then(|()| self.stream.flush())
+            .map_err(|e| format!("Failed to write request frame: {}", e));
+
+        rayforce_ffi::drop_obj(buf);
+        result
+    }
+
+    /// Read an `i64`-prefixed frame into a freshly allocated U8 vector object.
+    ///
+    /// The returned object must be freed with `drop_obj`. Safety: must be
+    /// called on the Rayforce thread.
+    unsafe fn read_frame(&mut self) -> Result<ObjP, String> {
+        let mut header = [0u8; 8];
+        self.stream
+            .read_exact(&mut header)
+            .map_err(|e| format!("Failed to read reply length prefix: {}", e))?;
+        let len = i64::from_le_bytes(header);
+        if len < 0 {
+            return Err(format!("Invalid reply length prefix: {}", len));
+        }
+
+        let buf = rayforce_ffi::vector(TYPE_U8, len);
+        if buf.is_null() {
+            return Err("Failed to allocate reply buffer".to_string());
+        }
+
+        let dst = std::slice::from_raw_parts_mut((*buf).data_ptr::<u8>() as *mut u8, len as usize);
+        if let Err(e) = self.stream.read_exact(dst) {
+            rayforce_ffi::drop_obj(buf);
+            return Err(format!("Failed to read reply frame: {}", e));
+        }
+
+        Ok(buf)
+    }
+}
+
+/// View the serialized byte buffer of a U8 vector as a slice.
+///
+/// Safety: `buf` must be a non-null U8 vector with at least `len` bytes.
+unsafe fn byte_slice<'a>(buf: ObjP, len: i64) -> &'a [u8] {
+    let ptr = (*buf).data_ptr::<u8>();
+    std::slice::from_raw_parts(ptr, len as usize)
+}
@@ -5,9 +5,13 @@
 //! with the Rayforce runtime.
 
 use crate::bridge::{QueryMeta, Row};
+use crate::conversion::Conversion;
 use serde::{Deserialize, Serialize};
+use tauri::Emitter;
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 use once_cell::sync::Lazy;
 
 fn get_bridge() -> &'static std::sync::Arc<crate::bridge::RayforceBridge> {
@@ -18,10 +22,104 @@ fn get_bridge() -> &'static std::sync::Arc<crate::bridge::RayforceBridge> {
 static SERVER_CONNECTIONS: Lazy<Mutex<HashMap<String, ServerConnectionInfo>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Reconnection policy for a remote server alias.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionRetryOptions {
+    /// Initial backoff between reconnect attempts.
+    pub min_backoff_ms: u64,
+    /// Ceiling the exponential backoff is clamped to.
+    pub max_backoff_ms: u64,
+    /// Maximum number of reconnect attempts before giving up (0 = unlimited).
+    pub max_retries: u32,
+    /// Timeout applied to each reconnect attempt made by the supervisor.
+    pub connection_timeout_ms: u64,
+    /// Interval between keep-alive pings over a healthy handle.
+    pub keep_alive_ms: u64,
+}
+
+impl Default for ConnectionRetryOptions {
+    fn default() -> Self {
+        Self {
+            min_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            max_retries: 10,
+            connection_timeout_ms: 5_000,
+            keep_alive_ms: 15_000,
+        }
+    }
+}
+
+/// A single remote endpoint an alias can be routed to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Endpoint {
+    pub host: String,
+    pub port: u16,
+}
+
+/// Current link state of an alias.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkStatus {
+    Connected,
+    Reconnecting,
+    Down,
+}
+
+/// Mutable health record for an alias, updated by its supervisor.
 #[derive(Debug, Clone)]
+struct AliasHealth {
+    status: LinkStatus,
+    /// Unix-epoch milliseconds of the last successful ping.
+    last_ping_ms: Option<u64>,
+    /// Round-trip latency of the last successful ping, in milliseconds.
+    latency_ms: Option<u64>,
+}
+
+impl Default for AliasHealth {
+    fn default() -> Self {
+        Self {
+            status: LinkStatus::Connected,
+            last_ping_ms: None,
+            latency_ms: None,
+        }
+    }
+}
+
 struct ServerConnectionInfo {
-    host: String,
-    port: u16,
+    /// Endpoints this alias may be routed to (first is the primary).
+    endpoints: Vec<Endpoint>,
+    /// Round-robin cursor over `endpoints`.
+    rr: Arc<AtomicUsize>,
+    retry: ConnectionRetryOptions,
+    /// Shared health record, updated by the supervisor.
+    health: Arc<Mutex<AliasHealth>>,
+    /// Serialises routed queries on this alias so a rebind and the query it
+    /// selected an endpoint for cannot be interleaved by another route.
+    route_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Supervisor task driving keep-alive + reconnect; aborted on disconnect.
+    supervisor: Option<tauri::async_runtime::JoinHandle<()>>,
+}
+
+/// Health snapshot of an alias returned by `list_connections`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionStatus {
+    pub alias: String,
+    pub host: String,
+    pub port: u16,
+    pub endpoints: Vec<Endpoint>,
+    pub status: LinkStatus,
+    pub last_ping_ms: Option<u64>,
+    pub latency_ms: Option<u64>,
+}
+
+/// Unix-epoch milliseconds, or 0 if the clock is before the epoch.
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
 }
 
 /// Execute a Rayfall query
@@ -34,6 +132,36 @@ pub async fn execute_query(query_id: String, code: String) -> Result<QueryMeta,
     get_bridge().execute_query(query_id, code).await
 }
 
+/// Execute a query with a timeout (milliseconds)
+///
+/// Like `execute_query`, but if the query runs longer than `timeout_ms` the
+/// caller is unblocked with `Err("timed out")` and the query is cancelled.
+/// Its result is freed instead of retained when the eval returns — either the
+/// cancellation token is seen in time and the eval is skipped/its result
+/// dropped, or it isn't and the stored handle gets released when the reply
+/// can't be delivered to the now-gone caller. Either way nothing leaks. The
+/// evaluation keeps running on the Rayforce thread; the timeout bounds the
+/// caller's wait, not the compute.
+#[tauri::command]
+pub async fn execute_query_timeout(
+    query_id: String,
+    code: String,
+    timeout_ms: u64,
+) -> Result<QueryMeta, String> {
+    log::debug!(
+        "execute_query_timeout: query_id={}, timeout_ms={}",
+        query_id,
+        timeout_ms
+    );
+    get_bridge()
+        .execute_query_with_timeout(
+            query_id,
+            code,
+            std::time::Duration::from_millis(timeout_ms),
+        )
+        .await
+}
+
 /// Get rows from a query result
 ///
 /// Fetches a chunk of rows from a previously executed query.
@@ -49,6 +177,50 @@ pub async fn get_rows(handle: u64, start: u64, count: u64) -> Result<Vec<Row>, S
     get_bridge().get_rows(handle, start, count).await
 }
 
+/// Get rows with per-column typed conversions
+///
+/// Like `get_rows`, but each named column in `conversions` is rendered
+/// according to its [`Conversion`] — e.g. a date column as an ISO-8601 string
+/// or a timestamp column as RFC3339. Columns absent from the map keep their
+/// raw encoding.
+#[tauri::command]
+pub async fn get_rows_typed(
+    handle: u64,
+    start: u64,
+    count: u64,
+    conversions: HashMap<String, Conversion>,
+) -> Result<Vec<Row>, String> {
+    log::debug!(
+        "get_rows_typed: handle={}, start={}, count={}, conversions={}",
+        handle,
+        start,
+        count,
+        conversions.len()
+    );
+    get_bridge()
+        .get_rows_typed(handle, start, count, conversions)
+        .await
+}
+
+/// Get a table result as a column-oriented payload
+///
+/// Returns one array per column instead of N row objects, reading each column
+/// once in bulk from the runtime's inline buffers. Much faster than `get_rows`
+/// for wide or deep table scrolls. `conversions` applies per-column typing as
+/// with `get_rows_typed`.
+#[tauri::command]
+pub async fn get_columns(
+    handle: u64,
+    start: u64,
+    count: u64,
+    conversions: HashMap<String, Conversion>,
+) -> Result<HashMap<String, Vec<serde_json::Value>>, String> {
+    log::debug!("get_columns: handle={}, start={}, count={}", handle, start, count);
+    get_bridge()
+        .get_columns(handle, start, count, conversions)
+        .await
+}
+
 /// Release a query result handle
 ///
 /// Frees the memory associated with a query result.
@@ -59,16 +231,254 @@ pub async fn release_handle(handle: u64) -> Result<(), String> {
     get_bridge().release_handle(handle).await
 }
 
-/// Cancel a pending query
+/// Cancel a pending query (cooperative, best-effort)
 ///
-/// Marks a query as cancelled. If the query is still running,
-/// its result will be discarded when it completes.
+/// Marks a query as cancelled. If the query is still running, its result will
+/// be discarded when it completes, but the running eval itself is not
+/// pre-empted — `librayforce` has no interrupt hook to stop it, so this is the
+/// accepted scope, not a pending gap. The same applies to
+/// `execute_query_timeout`'s deadline and to `query_pool`/`execute_batch`.
 #[tauri::command]
 pub async fn cancel_query(query_id: String) -> Result<(), String> {
     log::debug!("cancel_query: query_id={}", query_id);
     get_bridge().cancel_query(query_id).await
 }
 
+/// One streamed subscription chunk emitted to the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionEvent {
+    pub subscription_id: String,
+    /// Monotonically increasing per stream, starting at 0; a gap signals a
+    /// lost batch so the client can detect dropped chunks.
+    pub seq: u64,
+    pub rows: Vec<Row>,
+    /// True once the stream has ended (no more chunks will follow).
+    pub done: bool,
+}
+
+/// Default number of rows pushed per subscription chunk.
+const DEFAULT_CHUNK_SIZE: u64 = 1000;
+
+/// Subscribe to a query and stream incremental results over Tauri events.
+///
+/// Returns a `subscription_id`. As the Rayforce thread re-evaluates the query
+/// it pushes each new batch of rows as a discrete `subscription:<id>` event
+/// carrying a [`SubscriptionEvent`] tagged with a monotonically increasing
+/// `seq`, so the client can detect a dropped batch. This is what real-time
+/// tick tables need — a growing table rather than a frozen snapshot behind a
+/// handle — and replaces repeated `get_rows` polling. Tear down with
+/// `unsubscribe`.
+#[tauri::command]
+pub async fn subscribe(
+    app: tauri::AppHandle,
+    subscription_id: String,
+    code: String,
+    chunk_size: Option<u64>,
+) -> Result<String, String> {
+    log::debug!("subscribe: subscription_id={}", subscription_id);
+    let chunk = chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+    let mut rx = get_bridge().subscribe(subscription_id.clone(), code, chunk)?;
+
+    let id = subscription_id.clone();
+    let event = format!("subscription:{}", subscription_id);
+    tauri::async_runtime::spawn(async move {
+        let mut seq: u64 = 0;
+        while let Some(chunk) = rx.recv().await {
+            match chunk {
+                Ok(rows) => {
+                    let payload = SubscriptionEvent {
+                        subscription_id: subscription_id.clone(),
+                        seq,
+                        rows,
+                        done: false,
+                    };
+                    if app.emit(&event, payload).is_err() {
+                        break;
+                    }
+                    seq += 1;
+                }
+                Err(e) => {
+                    log::warn!("Subscription {} error: {}", subscription_id, e);
+                    break;
+                }
+            }
+        }
+        // Signal completion.
+        let _ = app.emit(
+            &event,
+            SubscriptionEvent {
+                subscription_id: subscription_id.clone(),
+                seq,
+                rows: Vec::new(),
+                done: true,
+            },
+        );
+    });
+
+    Ok(id)
+}
+
+/// Tear down a subscription and stop its producer.
+#[tauri::command]
+pub async fn unsubscribe(subscription_id: String) -> Result<(), String> {
+    log::debug!("unsubscribe: subscription_id={}", subscription_id);
+    get_bridge().unsubscribe(subscription_id)
+}
+
+/// Stream rows from a stored result to the frontend via events.
+///
+/// Server-push replacement for repeated `get_rows(handle, start, count)`
+/// polling. Each chunk is delivered as a `stream:<stream_id>` Tauri event
+/// carrying a [`SubscriptionEvent`]; `done` is set on the final event.
+#[tauri::command]
+pub async fn stream_rows(
+    app: tauri::AppHandle,
+    stream_id: String,
+    handle: u64,
+    start: u64,
+    total: u64,
+    chunk_size: Option<u64>,
+) -> Result<String, String> {
+    use futures::StreamExt;
+
+    log::debug!("stream_rows: stream_id={}, handle={}", stream_id, handle);
+    let chunk = chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+    let mut stream = get_bridge().stream_rows(handle, start, total, chunk)?;
+
+    let id = stream_id.clone();
+    let event = format!("stream:{}", stream_id);
+    tauri::async_runtime::spawn(async move {
+        let mut seq: u64 = 0;
+        while let Some(chunk) = stream.next().await {
+            match chunk {
+                Ok(rows) => {
+                    let payload = SubscriptionEvent {
+                        subscription_id: stream_id.clone(),
+                        seq,
+                        rows,
+                        done: false,
+                    };
+                    if app.emit(&event, payload).is_err() {
+                        break;
+                    }
+                    seq += 1;
+                }
+                Err(e) => {
+                    log::warn!("Stream {} error: {}", stream_id, e);
+                    break;
+                }
+            }
+        }
+        let _ = app.emit(
+            &event,
+            SubscriptionEvent {
+                subscription_id: stream_id.clone(),
+                seq,
+                rows: Vec::new(),
+                done: true,
+            },
+        );
+    });
+
+    Ok(id)
+}
+
+/// Execute several queries in one submission.
+///
+/// Returns one result per query, always in submission order. When `sequence`
+/// is true the queries run strictly one after another so a later query can
+/// depend on side effects (like a preceding `set`) of an earlier one; when
+/// false they are dispatched concurrently and the results are reordered back
+/// to submission order. Each successful query gets its own handle for
+/// pagination, so a dashboard can load many widgets in one round-trip.
+#[tauri::command]
+pub async fn execute_batch(
+    queries: Vec<(String, String)>,
+    sequence: bool,
+) -> Result<Vec<Result<QueryMeta, String>>, String> {
+    log::debug!("execute_batch: {} queries, sequence={}", queries.len(), sequence);
+
+    if sequence {
+        // Ordered execution with observable side effects, driven on the
+        // Rayforce thread as a single batch.
+        return get_bridge().execute_batch(queries).await;
+    }
+
+    // Parallel dispatch; `join_all` preserves submission order in its output.
+    let futures = queries
+        .into_iter()
+        .map(|(query_id, code)| get_bridge().execute_query(query_id, code));
+    Ok(futures::future::join_all(futures).await)
+}
+
+/// Connect to a remote Rayforce server over TCP
+///
+/// Opens a raw IPC socket to the server. Subsequent `query_remote` calls are framed
+/// with `ser_obj`/`size_obj`, sent over the socket, and the reply is decoded
+/// with `de_obj`. Only one remote connection is held at a time; connecting
+/// again replaces the previous link.
+#[tauri::command]
+pub async fn connect(host: String, port: u16) -> Result<(), String> {
+    log::info!("connect: host={}, port={}", host, port);
+    get_bridge().connect(host, port).await
+}
+
+/// Disconnect the remote Rayforce IPC connection
+#[tauri::command]
+pub async fn disconnect() -> Result<(), String> {
+    log::info!("disconnect");
+    get_bridge().disconnect().await
+}
+
+/// Execute a query against the connected remote server
+///
+/// When `wait` is true (the default RPC shape) this blocks for the decoded
+/// reply and returns its metadata. When false it is fire-and-forget — useful
+/// for writes and `set` operations — and returns no metadata.
+#[tauri::command]
+pub async fn query_remote(
+    query_id: String,
+    code: String,
+    wait: bool,
+) -> Result<Option<QueryMeta>, String> {
+    log::debug!("query_remote: query_id={}, wait={}", query_id, wait);
+    get_bridge().query_remote(query_id, code, wait).await
+}
+
+/// Completion payload emitted when an async query finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCompletion {
+    pub query_id: String,
+    pub result: Result<QueryMeta, String>,
+}
+
+/// Execute a query without blocking the invoking call.
+///
+/// Returns the `query_id` immediately and submits the work to the Rayforce
+/// thread. Completion is delivered as a `query-complete:<query_id>` Tauri
+/// event carrying a [`QueryCompletion`], so a long-running query never freezes
+/// the UI. Use `cancel_query(query_id)` to abandon the result — it is freed
+/// when the eval returns; the running evaluation itself is not pre-empted.
+#[tauri::command]
+pub async fn execute_query_async(
+    app: tauri::AppHandle,
+    query_id: String,
+    code: String,
+) -> Result<String, String> {
+    log::debug!("execute_query_async: query_id={}", query_id);
+    let id = query_id.clone();
+    tauri::async_runtime::spawn(async move {
+        let result = get_bridge().execute_query(query_id.clone(), code).await;
+        let event = format!("query-complete:{}", query_id);
+        if let Err(e) = app.emit(&event, QueryCompletion { query_id, result }) {
+            log::warn!("Failed to emit {}: {}", event, e);
+        }
+    });
+    Ok(id)
+}
+
 /// Result wrapper for scalar values
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScalarResult {
@@ -119,7 +529,14 @@ pub async fn execute_scalar(code: String) -> Result<ScalarResult, String> {
 /// the alias as a symbol in the local Rayforce environment. Once connected,
 /// queries can be routed to this server using the (@alias expr) syntax.
 #[tauri::command]
-pub async fn connect_server(alias: String, host: String, port: u16) -> Result<(), String> {
+pub async fn connect_server(
+    app: tauri::AppHandle,
+    alias: String,
+    host: String,
+    port: u16,
+    pool: Option<Vec<Endpoint>>,
+    options: Option<ConnectionRetryOptions>,
+) -> Result<(), String> {
     log::info!("connect_server: alias={}, host={}, port={}", alias, host, port);
 
     // Validate alias is a valid symbol name
@@ -127,36 +544,190 @@ pub async fn connect_server(alias: String, host: String, port: u16) -> Result<()
         return Err("Alias must start with a letter".to_string());
     }
 
-    // Store connection info
-    {
-        let mut connections = SERVER_CONNECTIONS.lock().unwrap();
-        connections.insert(
-            alias.clone(),
-            ServerConnectionInfo {
-                host: host.clone(),
-                port,
-            },
-        );
+    let retry = options.unwrap_or_default();
+
+    // The primary endpoint is always first; any additional pool members
+    // follow it and become candidates for routing and reconnection.
+    let mut endpoints = vec![Endpoint { host: host.clone(), port }];
+    if let Some(extra) = pool {
+        for ep in extra {
+            if !endpoints.iter().any(|e| e.host == ep.host && e.port == ep.port) {
+                endpoints.push(ep);
+            }
+        }
     }
 
     // Register the remote connection in Rayforce using hopen
     // The syntax (set alias (hopen "host:port")) creates a connection handle
-    let connect_code = format!("(set {} (hopen \"{}:{}\"))", alias, host, port);
+    let connect_code = hopen_code(&alias, &host, port);
 
-    match get_bridge().execute_query(format!("__connect__{}", alias), connect_code).await {
+    match get_bridge()
+        .execute_query(format!("__connect__{}", alias), connect_code)
+        .await
+    {
         Ok(_) => {
             log::info!("Successfully connected to remote server: {}", alias);
-            Ok(())
-        }
-        Err(e) => {
-            // Remove from tracking on failure
+            emit_link_state(&app, "server-connected", &alias, None);
+
+            let health = Arc::new(Mutex::new(AliasHealth::default()));
+            let rr = Arc::new(AtomicUsize::new(0));
+            let route_lock = Arc::new(tokio::sync::Mutex::new(()));
+
+            // Spawn the per-alias supervisor: keep-alive pings and, on a
+            // dropped handle, exponential-backoff reconnection.
+            let supervisor = spawn_supervisor(
+                app,
+                alias.clone(),
+                endpoints.clone(),
+                retry.clone(),
+                health.clone(),
+                Arc::clone(&route_lock),
+            );
+
             let mut connections = SERVER_CONNECTIONS.lock().unwrap();
-            connections.remove(&alias);
-            Err(format!("Failed to connect: {}", e))
+            connections.insert(
+                alias,
+                ServerConnectionInfo {
+                    endpoints,
+                    rr,
+                    retry,
+                    health,
+                    route_lock,
+                    supervisor: Some(supervisor),
+                },
+            );
+            Ok(())
         }
+        Err(e) => Err(format!("Failed to connect: {}", e)),
+    }
+}
+
+/// Rayfall code that (re)opens a handle and binds it to `alias`.
+fn hopen_code(alias: &str, host: &str, port: u16) -> String {
+    format!("(set {} (hopen \"{}:{}\"))", alias, host, port)
+}
+
+/// Link-state event payload emitted to the frontend.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LinkState {
+    alias: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+/// Emit a `server-*` link-state event for `alias`.
+fn emit_link_state(app: &tauri::AppHandle, event: &str, alias: &str, detail: Option<String>) {
+    let payload = LinkState {
+        alias: alias.to_string(),
+        detail,
+    };
+    if let Err(e) = app.emit(event, payload) {
+        log::warn!("Failed to emit {}: {}", event, e);
     }
 }
 
+/// Spawn the supervisor task for an alias.
+///
+/// Pings the handle every `keep_alive`, recording the round-trip latency and
+/// timestamp into the shared health record on success. On a closed/errored
+/// handle it marks the alias `Reconnecting`, emits `server-reconnecting` and
+/// re-runs the `hopen` bind with exponential backoff
+/// (`min(min_backoff * 2^attempt, max_backoff)`) up to `max_retries`, rotating
+/// through the endpoint pool on each attempt. It emits `server-connected` on
+/// success or `server-disconnected` (state `Down`) when the attempts are
+/// exhausted.
+///
+/// Each reconnect rebind is taken under `route_lock`, the same lock
+/// `query_pool` holds across its own rebind+query, so a supervisor reconnect
+/// can never interleave between a routed query's rebind and its evaluation.
+fn spawn_supervisor(
+    app: tauri::AppHandle,
+    alias: String,
+    endpoints: Vec<Endpoint>,
+    retry: ConnectionRetryOptions,
+    health: Arc<Mutex<AliasHealth>>,
+    route_lock: Arc<tokio::sync::Mutex<()>>,
+) -> tauri::async_runtime::JoinHandle<()> {
+    tauri::async_runtime::spawn(async move {
+        let keep_alive = std::time::Duration::from_millis(retry.keep_alive_ms.max(1));
+        let ping_code = format!("(@{} 1)", alias);
+
+        loop {
+            tokio::time::sleep(keep_alive).await;
+
+            // Keep-alive probe: a cheap round-trip over the handle.
+            let started = Instant::now();
+            let ping = get_bridge()
+                .execute_query(format!("__ping__{}", alias), ping_code.clone())
+                .await;
+            if ping.is_ok() {
+                let mut h = health.lock().unwrap();
+                h.status = LinkStatus::Connected;
+                h.last_ping_ms = Some(now_ms());
+                h.latency_ms = Some(started.elapsed().as_millis() as u64);
+                continue;
+            }
+
+            // Handle looks down: attempt to reconnect with backoff.
+            health.lock().unwrap().status = LinkStatus::Reconnecting;
+            emit_link_state(&app, "server-reconnecting", &alias, ping.err());
+
+            let mut attempt: u32 = 0;
+            let reconnected = loop {
+                if retry.max_retries != 0 && attempt >= retry.max_retries {
+                    break false;
+                }
+                let delay = backoff_delay(&retry, attempt);
+                tokio::time::sleep(delay).await;
+
+                // Rotate through the pool so a down primary falls over to a
+                // healthy replica. Held under the route lock so this rebind
+                // can't land between a routed query's own rebind and its eval.
+                let ep = &endpoints[attempt as usize % endpoints.len()];
+                let code = hopen_code(&alias, &ep.host, ep.port);
+                let _route = route_lock.lock().await;
+                match get_bridge()
+                    .execute_query_with_timeout(
+                        format!("__reconnect__{}", alias),
+                        code,
+                        std::time::Duration::from_millis(retry.connection_timeout_ms),
+                    )
+                    .await
+                {
+                    Ok(_) => break true,
+                    Err(e) => {
+                        log::warn!("Reconnect attempt {} for {} failed: {}", attempt + 1, alias, e);
+                        attempt += 1;
+                    }
+                }
+            };
+
+            if reconnected {
+                health.lock().unwrap().status = LinkStatus::Connected;
+                emit_link_state(&app, "server-connected", &alias, None);
+            } else {
+                health.lock().unwrap().status = LinkStatus::Down;
+                emit_link_state(
+                    &app,
+                    "server-disconnected",
+                    &alias,
+                    Some("reconnect attempts exhausted".to_string()),
+                );
+                break;
+            }
+        }
+    })
+}
+
+/// Exponential backoff delay for reconnect `attempt`, clamped to `max_backoff`.
+fn backoff_delay(retry: &ConnectionRetryOptions, attempt: u32) -> std::time::Duration {
+    let scaled = retry
+        .min_backoff_ms
+        .saturating_mul(1u64 << attempt.min(20));
+    std::time::Duration::from_millis(scaled.min(retry.max_backoff_ms))
+}
+
 /// Disconnect from a remote Rayforce server
 ///
 /// Closes the TCP connection and removes the alias from the Rayforce environment.
@@ -164,11 +735,16 @@ pub async fn connect_server(alias: String, host: String, port: u16) -> Result<()
 pub async fn disconnect_server(alias: String) -> Result<(), String> {
     log::info!("disconnect_server: alias={}", alias);
 
-    // Remove from tracking
+    // Remove from tracking and cancel the supervisor task cleanly.
     {
         let mut connections = SERVER_CONNECTIONS.lock().unwrap();
-        if connections.remove(&alias).is_none() {
-            return Err(format!("No connection with alias '{}'", alias));
+        match connections.remove(&alias) {
+            Some(mut info) => {
+                if let Some(supervisor) = info.supervisor.take() {
+                    supervisor.abort();
+                }
+            }
+            None => return Err(format!("No connection with alias '{}'", alias)),
         }
     }
 
@@ -190,3 +766,73 @@ pub async fn disconnect_server(alias: String) -> Result<(), String> {
         }
     }
 }
+
+/// List the health of all registered server connections.
+///
+/// Returns a snapshot, per alias, of the primary endpoint, the full pool, the
+/// current link state, the timestamp of the last successful keep-alive ping
+/// (Unix-epoch milliseconds) and its round-trip latency.
+#[tauri::command]
+pub async fn list_connections() -> Result<Vec<ConnectionStatus>, String> {
+    let connections = SERVER_CONNECTIONS.lock().unwrap();
+    let mut out: Vec<ConnectionStatus> = connections
+        .iter()
+        .map(|(alias, info)| {
+            let health = info.health.lock().unwrap().clone();
+            let primary = &info.endpoints[0];
+            ConnectionStatus {
+                alias: alias.clone(),
+                host: primary.host.clone(),
+                port: primary.port,
+                endpoints: info.endpoints.clone(),
+                status: health.status,
+                last_ping_ms: health.last_ping_ms,
+                latency_ms: health.latency_ms,
+            }
+        })
+        .collect();
+    out.sort_by(|a, b| a.alias.cmp(&b.alias));
+    Ok(out)
+}
+
+/// Route a query to one endpoint of a pooled alias and return the result handle.
+///
+/// Selects the next endpoint round-robin over the pool, rebinds the alias to it
+/// with `hopen`, then evaluates `(@alias expr)`. The rebind and the query run
+/// while holding the alias's route lock, so two concurrent `query_pool` calls
+/// on the same alias cannot interleave their rebinds — each query executes
+/// against the endpoint it selected. A single-endpoint alias is already bound
+/// to its one endpoint, so it skips the rebind and runs the query directly.
+#[tauri::command]
+pub async fn query_pool(
+    query_id: String,
+    alias: String,
+    expr: String,
+) -> Result<QueryMeta, String> {
+    let (endpoint, pooled, route_lock) = {
+        let connections = SERVER_CONNECTIONS.lock().unwrap();
+        let info = connections
+            .get(&alias)
+            .ok_or_else(|| format!("No connection with alias '{}'", alias))?;
+        let idx = info.rr.fetch_add(1, Ordering::Relaxed) % info.endpoints.len();
+        (
+            info.endpoints[idx].clone(),
+            info.endpoints.len() > 1,
+            Arc::clone(&info.route_lock),
+        )
+    };
+
+    // Hold the per-alias route lock across the rebind and the query so they are
+    // applied as one unit relative to any other routed query on this alias.
+    let _route = route_lock.lock().await;
+
+    if pooled {
+        let code = hopen_code(&alias, &endpoint.host, endpoint.port);
+        get_bridge()
+            .execute_query(format!("__route__{}", alias), code)
+            .await?;
+    }
+
+    let query = format!("(@{} {})", alias, expr);
+    execute_query(query_id, query).await
+}
@@ -0,0 +1,204 @@
+//! Typed value conversion for Rayforce objects
+//!
+//! Rayforce/k temporal types count from the epoch `2000-01-01`:
+//! - date (type -7) is a day count: `2000-01-01 + days`,
+//! - time/timestamp (types -8/-9) are nanosecond counts.
+//!
+//! `obj_to_json` emits these as raw integers, so the frontend has no way to
+//! render them as real dates. This module introduces a [`Conversion`] describing
+//! how a column's values should be rendered, and [`convert`] which applies it.
+//! A [`Conversion::Raw`] fallback preserves the existing integer behavior when
+//! no conversion is requested.
+
+use crate::rayforce_ffi::ObjP;
+use serde::{Deserialize, Serialize};
+
+/// Nanoseconds per second.
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+/// Seconds per day.
+const SECS_PER_DAY: i64 = 86_400;
+/// Days between the Unix epoch (1970-01-01) and the Rayforce epoch (2000-01-01).
+const DAYS_1970_TO_2000: i64 = 10_957;
+
+/// How a column's values should be converted for the frontend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Conversion {
+    /// Pass the value through unchanged (default, preserves integer behavior).
+    Raw,
+    /// Force the value to a JSON integer.
+    Integer,
+    /// Force the value to a JSON float.
+    Float,
+    /// Interpret as a boolean.
+    Boolean,
+    /// Interpret a day count as an ISO-8601 date (`%Y-%m-%d`).
+    Date,
+    /// Interpret a nanosecond count as a time-of-day string.
+    Time,
+    /// Interpret a nanosecond count as an RFC3339 timestamp.
+    Timestamp,
+    /// Interpret a day count as a date using a custom format string.
+    DateFmt(String),
+    /// Interpret a nanosecond count as a timestamp using a custom format string.
+    TimestampFmt(String),
+}
+
+/// Map a Rayforce type code to a canonical, frontend-facing type string.
+pub fn canonical_type(type_: i8) -> &'static str {
+    match type_.abs() {
+        1 => "boolean",
+        2 => "byte",
+        3 => "i16",
+        4 => "i32",
+        5 => "i64",
+        6 => "symbol",
+        7 => "date",
+        8 => "time",
+        9 => "timestamp",
+        10 => "f64",
+        11 => "guid",
+        12 => "char",
+        0 => "list",
+        _ => "unknown",
+    }
+}
+
+/// Convert a Rayforce atom to a JSON value according to `conv`.
+///
+/// Returns [`serde_json::Value::Null`] for a null object. Numeric extraction
+/// mirrors the atom accessors used elsewhere in the bridge. [`Conversion::Raw`]
+/// yields `Null` here; [`convert_or_raw`] supplies the raw encoding for it.
+pub fn convert(obj: ObjP, conv: &Conversion) -> serde_json::Value {
+    if obj.is_null() {
+        return serde_json::Value::Null;
+    }
+    let obj_ref = unsafe { &*obj };
+
+    match conv {
+        Conversion::Raw => serde_json::Value::Null, // handled by caller's fallback
+        Conversion::Integer => serde_json::json!(unsafe { obj_ref.as_i64() }),
+        Conversion::Float => serde_json::json!(unsafe { obj_ref.as_f64() }),
+        Conversion::Boolean => serde_json::Value::Bool(unsafe { obj_ref.as_i64() } != 0),
+        Conversion::Date => {
+            let days = unsafe { obj_ref.as_i64() };
+            serde_json::Value::String(format_date(days, "%Y-%m-%d"))
+        }
+        Conversion::DateFmt(fmt) => {
+            let days = unsafe { obj_ref.as_i64() };
+            serde_json::Value::String(format_date(days, fmt))
+        }
+        Conversion::Time => {
+            let nanos = unsafe { obj_ref.as_i64() };
+            serde_json::Value::String(format_timestamp(nanos, "%H:%M:%S.%9f"))
+        }
+        Conversion::Timestamp => {
+            let nanos = unsafe { obj_ref.as_i64() };
+            serde_json::Value::String(format_timestamp(nanos, "%Y-%m-%dT%H:%M:%S.%9fZ"))
+        }
+        Conversion::TimestampFmt(fmt) => {
+            let nanos = unsafe { obj_ref.as_i64() };
+            serde_json::Value::String(format_timestamp(nanos, fmt))
+        }
+    }
+}
+
+/// Apply `conv` to a cell, falling back to the plain JSON encoding for
+/// [`Conversion::Raw`] or when the object is not an atom.
+pub fn convert_or_raw(
+    obj: ObjP,
+    conv: Option<&Conversion>,
+    raw: impl FnOnce(ObjP) -> Result<serde_json::Value, String>,
+) -> Result<serde_json::Value, String> {
+    match conv {
+        Some(c) if !matches!(c, Conversion::Raw)
+            && !obj.is_null()
+            && unsafe { (*obj).type_ } < 0 =>
+        {
+            Ok(convert(obj, c))
+        }
+        _ => raw(obj),
+    }
+}
+
+/// Format a day count (days since 2000-01-01) using a small strftime subset.
+fn format_date(days: i64, fmt: &str) -> String {
+    let (y, m, d) = civil_from_days(days + DAYS_1970_TO_2000);
+    render(fmt, y, m, d, 0, 0, 0, 0)
+}
+
+/// Format a nanosecond count (since 2000-01-01) using a small strftime subset.
+fn format_timestamp(nanos: i64, fmt: &str) -> String {
+    let total_secs = nanos.div_euclid(NANOS_PER_SEC) + DAYS_1970_TO_2000 * SECS_PER_DAY;
+    let sub_nanos = nanos.rem_euclid(NANOS_PER_SEC);
+    let days = total_secs.div_euclid(SECS_PER_DAY);
+    let secs_of_day = total_secs.rem_euclid(SECS_PER_DAY);
+    let (y, m, d) = civil_from_days(days);
+    let hh = secs_of_day / 3600;
+    let mm = (secs_of_day % 3600) / 60;
+    let ss = secs_of_day % 60;
+    render(fmt, y, m, d, hh, mm, ss, sub_nanos)
+}
+
+/// Civil date from days since the Unix epoch (Howard Hinnant's algorithm).
+fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// Minimal strftime-style renderer supporting the tokens this crate needs:
+/// `%Y %m %d %H %M %S`, `%3f` (millis), `%6f` (micros), `%9f` (nanos), `%%`.
+fn render(
+    fmt: &str,
+    y: i64,
+    mo: i64,
+    d: i64,
+    h: i64,
+    mi: i64,
+    s: i64,
+    nanos: i64,
+) -> String {
+    let mut out = String::with_capacity(fmt.len() + 8);
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&format!("{:04}", y)),
+            Some('m') => out.push_str(&format!("{:02}", mo)),
+            Some('d') => out.push_str(&format!("{:02}", d)),
+            Some('H') => out.push_str(&format!("{:02}", h)),
+            Some('M') => out.push_str(&format!("{:02}", mi)),
+            Some('S') => out.push_str(&format!("{:02}", s)),
+            Some('3') if chars.peek() == Some(&'f') => {
+                chars.next();
+                out.push_str(&format!("{:03}", nanos / 1_000_000));
+            }
+            Some('6') if chars.peek() == Some(&'f') => {
+                chars.next();
+                out.push_str(&format!("{:06}", nanos / 1_000));
+            }
+            Some('9') if chars.peek() == Some(&'f') => {
+                chars.next();
+                out.push_str(&format!("{:09}", nanos));
+            }
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
@@ -1,6 +1,9 @@
 mod bridge;
+mod client;
 pub mod commands;
+pub mod conversion;
 mod rayforce_ffi;
+pub mod reactor;
 
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
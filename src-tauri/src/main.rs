@@ -2,7 +2,9 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod bridge;
+mod client;
 mod commands;
+mod conversion;
 mod rayforce_ffi;
 
 use bridge::RayforceBridge;
@@ -29,10 +31,25 @@ fn main() {
         .plugin(tauri_plugin_shell::init())
         .invoke_handler(tauri::generate_handler![
             commands::execute_query,
+            commands::execute_query_async,
+            commands::execute_query_timeout,
             commands::execute_scalar,
             commands::get_rows,
+            commands::get_rows_typed,
+            commands::get_columns,
             commands::release_handle,
             commands::cancel_query,
+            commands::connect,
+            commands::disconnect,
+            commands::query_remote,
+            commands::subscribe,
+            commands::unsubscribe,
+            commands::stream_rows,
+            commands::execute_batch,
+            commands::connect_server,
+            commands::disconnect_server,
+            commands::list_connections,
+            commands::query_pool,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
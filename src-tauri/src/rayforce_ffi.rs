@@ -96,6 +96,27 @@ impl PollEvents {
     pub const READ: Self = Self(1);
     pub const WRITE: Self = Self(4);
     pub const ERROR: Self = Self(8);
+
+    /// Whether every bit in `other` is set in `self`.
+    #[inline]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for PollEvents {
+    type Output = Self;
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for PollEvents {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
 }
 
 /// Opaque selector structure
@@ -0,0 +1,146 @@
+//! Safe reactor wrapper over the raw poll FFI
+//!
+//! The `poll_*` functions and [`PollRegistry`](rayforce_ffi::PollRegistry) are
+//! exposed as raw `unsafe extern "C"` with bare function-pointer callbacks,
+//! which is error-prone for anyone wiring up sockets or files. This module
+//! provides a memory-safe [`Reactor`] that owns the
+//! [`PollP`](rayforce_ffi::PollP) with RAII (destroyed on [`Drop`]) and lets
+//! callers register an fd with a Rust closure instead of raw
+//! `PollEvtsFn`/`PollDataFn` pointers.
+//!
+//! The boxed closure is stored behind the registry's `data: *mut c_void`
+//! field and dispatched through a single generic trampoline shim. Registering
+//! returns a [`Registration`] token that deregisters the fd when dropped. The
+//! API mirrors the rustix/mio epoll safety experiments:
+//! [`PollEvents`](rayforce_ffi::PollEvents) is a bitflags-style type with
+//! `READ`/`WRITE`/`ERROR`, and [`Reactor::run`] borrows the reactor for the
+//! duration of the blocking loop.
+
+use crate::rayforce_ffi::{
+    self, OptionT, PollEvents, PollP, PollRegistry, SelectorP, SelectorType,
+};
+use std::ffi::c_void;
+
+/// A callback invoked when a registered fd becomes ready.
+pub trait Handler: Send {
+    /// Called when this fd is ready. The poll does not report which events
+    /// fired, so `events` is always [`PollEvents::READ`].
+    fn on_ready(&mut self, events: PollEvents);
+}
+
+impl<F: FnMut(PollEvents) + Send> Handler for F {
+    fn on_ready(&mut self, events: PollEvents) {
+        self(events)
+    }
+}
+
+/// A safe owner of a Rayforce poll instance.
+pub struct Reactor {
+    poll: PollP,
+}
+
+impl Reactor {
+    /// Create a new reactor, allocating the underlying poll instance.
+    pub fn new() -> Result<Self, String> {
+        let poll = unsafe { rayforce_ffi::poll_create() };
+        if poll.is_null() {
+            return Err("poll_create returned null".to_string());
+        }
+        Ok(Self { poll })
+    }
+
+    /// Register `fd` with the given interest and handler.
+    ///
+    /// The handler is boxed and dispatched through a generic trampoline; the
+    /// returned [`Registration`] deregisters the fd (and frees the handler)
+    /// when it is dropped.
+    pub fn register(
+        &self,
+        fd: i64,
+        type_: SelectorType,
+        events: PollEvents,
+        handler: Box<dyn Handler>,
+    ) -> Result<Registration<'_>, String> {
+        // Double-box so the fat trait-object pointer becomes a thin pointer
+        // we can stash in the registry's `data` field.
+        let boxed: *mut Box<dyn Handler> = Box::into_raw(Box::new(handler));
+
+        let mut registry = PollRegistry {
+            fd,
+            type_,
+            events,
+            data_fn: Some(trampoline),
+            data: boxed as *mut c_void,
+            ..PollRegistry::default()
+        };
+
+        let id = unsafe { rayforce_ffi::poll_register(self.poll, &mut registry) };
+        if id < 0 {
+            // Reclaim the box on failure so we don't leak.
+            unsafe { drop(Box::from_raw(boxed)) };
+            return Err(format!("poll_register failed for fd {}", fd));
+        }
+
+        Ok(Registration {
+            reactor: self,
+            id,
+            handler: boxed,
+        })
+    }
+
+    /// Run the blocking event loop until [`Reactor::exit`] is called.
+    ///
+    /// Borrows the reactor for the duration so registrations can't be torn
+    /// down from another path while the loop is driving them.
+    pub fn run(&self) -> i64 {
+        unsafe { rayforce_ffi::poll_run(self.poll) }
+    }
+
+    /// Ask the running event loop to exit with `code`.
+    pub fn exit(&self, code: i64) {
+        unsafe { rayforce_ffi::poll_exit(self.poll, code) };
+    }
+}
+
+impl Drop for Reactor {
+    fn drop(&mut self) {
+        unsafe { rayforce_ffi::poll_destroy(self.poll) };
+    }
+}
+
+/// A live fd registration. Deregisters and frees its handler on drop.
+pub struct Registration<'r> {
+    reactor: &'r Reactor,
+    id: i64,
+    handler: *mut Box<dyn Handler>,
+}
+
+impl Registration<'_> {
+    /// The selector id assigned by the poll for this registration.
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl Drop for Registration<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            rayforce_ffi::poll_deregister(self.reactor.poll, self.id);
+            drop(Box::from_raw(self.handler));
+        }
+    }
+}
+
+/// Single generic trampoline that recovers the boxed handler from `data` and
+/// dispatches the ready event to it.
+extern "C" fn trampoline(poll: PollP, _selector: SelectorP, data: *mut c_void) -> OptionT {
+    if data.is_null() {
+        return OptionT { ok: 0, value: 0 };
+    }
+    let handler = unsafe { &mut *(data as *mut Box<dyn Handler>) };
+    // The poll does not expose the specific ready mask to the data callback,
+    // so report READ — the common interest for socket/file registrations.
+    handler.on_ready(PollEvents::READ);
+    let _ = poll;
+    OptionT { ok: 1, value: 0 }
+}